@@ -9,8 +9,12 @@ mod lib {
     pub use std::*;
 }
 
-use lib::cell::RefCell;
+use lib::cell::{Cell, RefCell};
+use lib::marker::PhantomData;
+use lib::mem;
 use lib::ops::{Deref, DerefMut};
+use lib::ptr::NonNull;
+use lib::time::Duration;
 
 /// A trait for obtaining an immutable or mutable reference on types that allow interior mutability.
 pub trait InteriorMut<T: ?Sized> {
@@ -49,6 +53,181 @@ pub trait InteriorMut<T: ?Sized> {
 
     /// Mutably borrows the internal value from an immutable reference.
     fn borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>>;
+
+    /// Immutably borrows the internal value and projects it to a sub-field via `f`,
+    /// keeping the original guard alive for as long as the returned [`MappedRef`] lives.
+    fn borrow_int_map<U: ?Sized>(
+        &self,
+        f: impl FnOnce(&T) -> &U,
+    ) -> Result<MappedRef<'_, Self, T, U>, Self::Error<'_>> {
+        let guard = self.borrow_int()?;
+        let ptr = NonNull::from(f(&*guard));
+        Ok(MappedRef {
+            _guard: guard,
+            ptr,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Mutably borrows the internal value and projects it to a sub-field via `f`,
+    /// keeping the original guard alive for as long as the returned [`MappedRefMut`] lives.
+    fn borrow_int_map_mut<U: ?Sized>(
+        &self,
+        f: impl FnOnce(&mut T) -> &mut U,
+    ) -> Result<MappedRefMut<'_, Self, T, U>, Self::ErrorMut<'_>> {
+        let mut guard = self.borrow_int_mut()?;
+        let ptr = NonNull::from(f(&mut *guard));
+        Ok(MappedRefMut {
+            _guard: guard,
+            ptr,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A guard produced by [`InteriorMut::borrow_int_map`] that projects an immutable borrow
+/// to a sub-field while keeping the original guard alive.
+pub struct MappedRef<'a, S: InteriorMut<T> + ?Sized + 'a, T: ?Sized + 'a, U: ?Sized> {
+    _guard: S::Ref<'a>,
+    ptr: NonNull<U>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, S: InteriorMut<T> + ?Sized + 'a, T: ?Sized + 'a, U: ?Sized> Deref
+    for MappedRef<'a, S, T, U>
+{
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // SAFETY: `ptr` was derived from `guard`'s contents and `guard` is kept alive
+        // for as long as this `MappedRef` exists, so the pointee is still valid.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+/// A guard produced by [`InteriorMut::borrow_int_map_mut`] that projects a mutable borrow
+/// to a sub-field while keeping the original guard alive.
+///
+/// `U` must be invariant here, not covariant: this type hands out `&mut U` through
+/// `DerefMut`, so shrinking `U`'s lifetime via subtyping (as a covariant `NonNull<U>`
+/// would allow) would let a caller write a short-lived reference through the guard,
+/// drop the guard, and read it back out as the original, longer-lived `U` — the same
+/// reasoning that makes `std::cell::RefMut` invariant over its target.
+///
+/// ```compile_fail
+/// use std::cell::RefCell;
+/// use interior_mut::{InteriorMut, MappedRefMut};
+///
+/// fn assert_invariant<'short>(
+///     long: MappedRefMut<'static, RefCell<&'static str>, &'static str, &'static str>,
+/// ) {
+///     // If `MappedRefMut` were covariant in `U`, this would type-check, letting a
+///     // `&'static str`-yielding guard pose as a `&'short str`-yielding one and enabling
+///     // a use-after-free through `DerefMut`. It must fail to compile.
+///     let _short: MappedRefMut<'static, RefCell<&'static str>, &'static str, &'short str> =
+///         long;
+/// }
+/// ```
+pub struct MappedRefMut<'a, S: InteriorMut<T> + ?Sized + 'a, T: ?Sized + 'a, U: ?Sized> {
+    _guard: S::RefMut<'a>,
+    ptr: NonNull<U>,
+    _marker: PhantomData<(*mut U, T)>,
+}
+
+impl<'a, S: InteriorMut<T> + ?Sized + 'a, T: ?Sized + 'a, U: ?Sized> Deref
+    for MappedRefMut<'a, S, T, U>
+{
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // SAFETY: see `MappedRef::deref`.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<'a, S: InteriorMut<T> + ?Sized + 'a, T: ?Sized + 'a, U: ?Sized> DerefMut
+    for MappedRefMut<'a, S, T, U>
+{
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: see `MappedRef::deref`; `&mut self` ensures exclusive access.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod mapped_ref_tests {
+    use super::*;
+    use std::sync::{Mutex, RwLock};
+
+    #[test]
+    fn refcell_maps_tuple_field() {
+        let container = RefCell::new((1u32, 'a'));
+        let mapped = container.borrow_int_map(|pair| &pair.0).unwrap();
+        assert_eq!(*mapped, 1);
+    }
+
+    #[test]
+    fn refcell_maps_and_mutates_tuple_field() {
+        let container = RefCell::new((1u32, 'a'));
+        {
+            let mut mapped = container.borrow_int_map_mut(|pair| &mut pair.0).unwrap();
+            *mapped += 1;
+        }
+        assert_eq!(container.borrow_int().unwrap().0, 2);
+    }
+
+    #[test]
+    fn mutex_map_keeps_lock_held_while_alive() {
+        let container = Mutex::new((1u32, 'a'));
+        let mapped = container.borrow_int_map(|pair| &pair.0).unwrap();
+        assert!(container.try_lock().is_err());
+        assert_eq!(*mapped, 1);
+        drop(mapped);
+        assert!(container.try_lock().is_ok());
+    }
+
+    #[test]
+    fn rwlock_maps_and_mutates_tuple_field() {
+        let container = RwLock::new((1u32, 'a'));
+        {
+            let mut mapped = container.borrow_int_map_mut(|pair| &mut pair.0).unwrap();
+            *mapped += 1;
+        }
+        assert_eq!(container.borrow_int().unwrap().0, 2);
+    }
+}
+
+/// A trait for attempting to obtain an immutable or mutable reference without blocking.
+///
+/// Unlike [`InteriorMut::borrow_int`]/[`InteriorMut::borrow_int_mut`], which block on
+/// `Mutex`/`RwLock`-backed containers, `try_borrow_int`/`try_borrow_int_mut` return
+/// immediately with an error if the borrow cannot be granted right away.
+pub trait TryInteriorMut<T: ?Sized>: InteriorMut<T> {
+    /// The error type for non-blocking immutable borrows.
+    #[cfg(not(feature = "std"))]
+    type TryError<'a>: core::fmt::Debug + core::fmt::Display
+    where
+        Self: 'a;
+    #[cfg(feature = "std")]
+    type TryError<'a>: std::error::Error
+    where
+        Self: 'a;
+
+    /// The error type for non-blocking mutable borrows.
+    #[cfg(not(feature = "std"))]
+    type TryErrorMut<'a>: core::fmt::Debug + core::fmt::Display
+    where
+        Self: 'a;
+    #[cfg(feature = "std")]
+    type TryErrorMut<'a>: std::error::Error
+    where
+        Self: 'a;
+
+    /// Immutably borrows the internal value without blocking.
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>>;
+
+    /// Mutably borrows the internal value without blocking.
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>>;
 }
 
 /// A reference that can be downgraded to a weak variant.
@@ -69,6 +248,248 @@ pub trait WeakReference<T: ?Sized> {
     fn upgrade(&self) -> Option<Self::Strong>;
 }
 
+/// A trait for containers that provide interior mutability by swapping whole values
+/// rather than handing out references, such as [`Cell<T>`].
+///
+/// This is implemented for `Cell` and for each [`InteriorMut`] container this crate
+/// provides (`RefCell`, `Mutex`, `RwLock`, `Waitable`, and `Rc`/`Arc` of any of those), rather than
+/// as a single blanket impl over `InteriorMut<T>`: a blanket impl would conflict under
+/// coherence with the concrete `Cell` impl, since nothing prevents a downstream crate
+/// from also implementing `InteriorMut` for `Cell` (there is no negative-impl bound to
+/// rule that out). Consequently a user-defined `InteriorMut` type does **not** get
+/// `InteriorSwap` for free and must implement it itself. This narrower coverage is the
+/// intended final shape of this trait, not a placeholder pending a blanket impl.
+///
+/// # Panics
+///
+/// `Cell`'s impl never panics. The impls for `RefCell`, `Mutex`, `RwLock`, `Waitable`, and
+/// their `Rc`/`Arc` wrappers expose this trait's infallible signatures over a borrow that
+/// can fail, so `set`/`replace`/`get`/`take` panic if that borrow fails — a `RefCell` that
+/// is already borrowed, or a poisoned `Mutex`/`RwLock`. The lock-based impls do not fail
+/// on a lock held by another thread; they block until it is released.
+pub trait InteriorSwap<T> {
+    /// Sets the contained value, dropping the old one.
+    ///
+    /// # Panics
+    ///
+    /// See the trait-level documentation.
+    fn set(&self, value: T);
+
+    /// Replaces the contained value and returns the old one.
+    ///
+    /// # Panics
+    ///
+    /// See the trait-level documentation.
+    fn replace(&self, value: T) -> T;
+
+    /// Returns a copy of the contained value.
+    ///
+    /// # Panics
+    ///
+    /// See the trait-level documentation.
+    fn get(&self) -> T
+    where
+        T: Copy;
+
+    /// Takes the contained value, leaving `T::default()` in its place.
+    ///
+    /// # Panics
+    ///
+    /// See the trait-level documentation.
+    fn take(&self) -> T
+    where
+        T: Default;
+
+    /// Updates the contained value by applying `f` to a copy of it.
+    ///
+    /// # Panics
+    ///
+    /// See the trait-level documentation.
+    fn update(&self, f: impl FnOnce(T) -> T)
+    where
+        T: Copy,
+    {
+        self.set(f(self.get()));
+    }
+}
+
+impl<T> InteriorSwap<T> for Cell<T> {
+    fn set(&self, value: T) {
+        Cell::set(self, value)
+    }
+
+    fn replace(&self, value: T) -> T {
+        Cell::replace(self, value)
+    }
+
+    fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        Cell::get(self)
+    }
+
+    fn take(&self) -> T
+    where
+        T: Default,
+    {
+        Cell::take(self)
+    }
+}
+
+/// Sets `container`'s value via its [`InteriorMut`] mutable borrow.
+fn interior_mut_set<T, G: InteriorMut<T> + ?Sized>(container: &G, value: T) {
+    let mut guard = container
+        .borrow_int_mut()
+        .expect("InteriorSwap::set: could not borrow value mutably");
+    *guard = value;
+}
+
+/// Replaces `container`'s value via its [`InteriorMut`] mutable borrow.
+fn interior_mut_replace<T, G: InteriorMut<T> + ?Sized>(container: &G, value: T) -> T {
+    let mut guard = container
+        .borrow_int_mut()
+        .expect("InteriorSwap::replace: could not borrow value mutably");
+    mem::replace(&mut *guard, value)
+}
+
+/// Copies `container`'s value via its [`InteriorMut`] immutable borrow.
+fn interior_mut_get<T: Copy, G: InteriorMut<T> + ?Sized>(container: &G) -> T {
+    let guard = container
+        .borrow_int()
+        .expect("InteriorSwap::get: value already mutably borrowed");
+    *guard
+}
+
+/// Implements [`InteriorSwap`] for an `InteriorMut<T>` container in terms of
+/// [`interior_mut_set`]/[`interior_mut_replace`]/[`interior_mut_get`], to avoid
+/// repeating the same forwarding impl for every container this crate provides.
+macro_rules! impl_interior_swap_via_interior_mut {
+    ($ty:ty) => {
+        impl<T> InteriorSwap<T> for $ty {
+            fn set(&self, value: T) {
+                interior_mut_set(self, value)
+            }
+
+            fn replace(&self, value: T) -> T {
+                interior_mut_replace(self, value)
+            }
+
+            fn get(&self) -> T
+            where
+                T: Copy,
+            {
+                interior_mut_get(self)
+            }
+
+            fn take(&self) -> T
+            where
+                T: Default,
+            {
+                InteriorSwap::replace(self, T::default())
+            }
+        }
+    };
+    ($ty:ty, $generic:ident) => {
+        impl<T, $generic: InteriorMut<T> + ?Sized> InteriorSwap<T> for $ty {
+            fn set(&self, value: T) {
+                interior_mut_set(self, value)
+            }
+
+            fn replace(&self, value: T) -> T {
+                interior_mut_replace(self, value)
+            }
+
+            fn get(&self) -> T
+            where
+                T: Copy,
+            {
+                interior_mut_get(self)
+            }
+
+            fn take(&self) -> T
+            where
+                T: Default,
+            {
+                InteriorSwap::replace(self, T::default())
+            }
+        }
+    };
+}
+
+impl_interior_swap_via_interior_mut!(RefCell<T>);
+
+#[cfg(feature = "std")]
+impl_interior_swap_via_interior_mut!(std::sync::Mutex<T>);
+
+#[cfg(feature = "std")]
+impl_interior_swap_via_interior_mut!(std::sync::RwLock<T>);
+
+#[cfg(feature = "std")]
+impl_interior_swap_via_interior_mut!(std::rc::Rc<I>, I);
+
+#[cfg(feature = "std")]
+impl_interior_swap_via_interior_mut!(std::sync::Arc<I>, I);
+
+/// A trait for borrowing mutably once a predicate over the contained value holds,
+/// analogous to pairing a `Mutex` with a `Condvar`.
+pub trait WaitableInteriorMut<T: ?Sized>: InteriorMut<T> {
+    /// Blocks until `condition` returns `true`, then returns a mutable borrow.
+    fn borrow_int_mut_when(
+        &self,
+        condition: impl FnMut(&mut T) -> bool,
+    ) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>>;
+
+    /// Like [`Self::borrow_int_mut_when`], but gives up once `dur` has elapsed. The
+    /// returned `bool` is `true` if the wait timed out before `condition` held.
+    fn borrow_int_mut_when_timeout(
+        &self,
+        condition: impl FnMut(&mut T) -> bool,
+        dur: Duration,
+    ) -> Result<(Self::RefMut<'_>, bool), Self::ErrorMut<'_>>;
+
+    /// Wakes up one waiter blocked in [`Self::borrow_int_mut_when`].
+    fn notify(&self);
+
+    /// Wakes up all waiters blocked in [`Self::borrow_int_mut_when`].
+    fn notify_all(&self);
+}
+
+/// For `no_std` builds, where there is no `Condvar` to block on, `borrow_int_mut_when`
+/// degrades to asserting the predicate already holds — matching how minimal environments
+/// cannot wait for another thread to change the value. `borrow_int_mut_when_timeout`
+/// does not assert: it returns at once, reporting a timeout if the predicate does not
+/// hold. `notify`/`notify_all` do nothing. This impl is unavailable under the `std`
+/// feature; use [`Waitable`] there instead.
+#[cfg(not(feature = "std"))]
+impl<T: ?Sized> WaitableInteriorMut<T> for RefCell<T> {
+    fn borrow_int_mut_when(
+        &self,
+        mut condition: impl FnMut(&mut T) -> bool,
+    ) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>> {
+        let mut guard = self.borrow_int_mut()?;
+        assert!(
+            condition(&mut guard),
+            "WaitableInteriorMut::borrow_int_mut_when: condition not satisfied and a RefCell cannot block"
+        );
+        Ok(guard)
+    }
+
+    fn borrow_int_mut_when_timeout(
+        &self,
+        mut condition: impl FnMut(&mut T) -> bool,
+        _dur: Duration,
+    ) -> Result<(Self::RefMut<'_>, bool), Self::ErrorMut<'_>> {
+        let mut guard = self.borrow_int_mut()?;
+        let satisfied = condition(&mut guard);
+        Ok((guard, !satisfied))
+    }
+
+    fn notify(&self) {}
+
+    fn notify_all(&self) {}
+}
+
 impl<T: ?Sized> InteriorMut<T> for RefCell<T> {
     type Ref<'a> = lib::cell::Ref<'a, T> where T: 'a;
     type RefMut<'a> = lib::cell::RefMut<'a, T> where T: 'a;
@@ -84,6 +505,19 @@ impl<T: ?Sized> InteriorMut<T> for RefCell<T> {
     }
 }
 
+impl<T: ?Sized> TryInteriorMut<T> for RefCell<T> {
+    type TryError<'a> = lib::cell::BorrowError where T: 'a;
+    type TryErrorMut<'a> = lib::cell::BorrowMutError where T: 'a;
+
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>> {
+        RefCell::try_borrow(self)
+    }
+
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>> {
+        RefCell::try_borrow_mut(self)
+    }
+}
+
 #[cfg(feature = "std")]
 impl<T: ?Sized> InteriorMut<T> for std::sync::Mutex<T> {
     type Ref<'a> = std::sync::MutexGuard<'a, T> where T: 'a;
@@ -100,6 +534,20 @@ impl<T: ?Sized> InteriorMut<T> for std::sync::Mutex<T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: ?Sized> TryInteriorMut<T> for std::sync::Mutex<T> {
+    type TryError<'a> = std::sync::TryLockError<std::sync::MutexGuard<'a, T>> where T: 'a;
+    type TryErrorMut<'a> = std::sync::TryLockError<std::sync::MutexGuard<'a, T>> where T: 'a;
+
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>> {
+        self.try_lock()
+    }
+
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>> {
+        self.try_lock()
+    }
+}
+
 #[cfg(feature = "std")]
 impl<T: ?Sized> InteriorMut<T> for std::sync::RwLock<T> {
     type Ref<'a> = std::sync::RwLockReadGuard<'a, T> where T: 'a;
@@ -116,6 +564,20 @@ impl<T: ?Sized> InteriorMut<T> for std::sync::RwLock<T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: ?Sized> TryInteriorMut<T> for std::sync::RwLock<T> {
+    type TryError<'a> = std::sync::TryLockError<std::sync::RwLockReadGuard<'a, T>> where T: 'a;
+    type TryErrorMut<'a> = std::sync::TryLockError<std::sync::RwLockWriteGuard<'a, T>> where T: 'a;
+
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>> {
+        self.try_read()
+    }
+
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>> {
+        self.try_write()
+    }
+}
+
 #[cfg(feature = "std")]
 impl<T: ?Sized, I: InteriorMut<T> + ?Sized> InteriorMut<T> for std::rc::Rc<I> {
     type Ref<'a> = I::Ref<'a>
@@ -143,6 +605,25 @@ impl<T: ?Sized, I: InteriorMut<T> + ?Sized> InteriorMut<T> for std::rc::Rc<I> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: ?Sized, I: TryInteriorMut<T> + ?Sized> TryInteriorMut<T> for std::rc::Rc<I> {
+    type TryError<'a> = I::TryError<'a>
+    where
+        Self: 'a, I: 'a;
+
+    type TryErrorMut<'a> = I::TryErrorMut<'a>
+    where
+        Self: 'a, I: 'a;
+
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>> {
+        self.deref().try_borrow_int()
+    }
+
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>> {
+        self.deref().try_borrow_int_mut()
+    }
+}
+
 #[cfg(feature = "std")]
 impl<T: ?Sized, I: InteriorMut<T> + ?Sized> StrongReference<T> for std::rc::Rc<I> {
     type Weak = std::rc::Weak<I>;
@@ -160,3 +641,389 @@ impl<T: ?Sized, I: InteriorMut<T> + ?Sized> WeakReference<T> for std::rc::Weak<I
         std::rc::Weak::upgrade(self)
     }
 }
+
+#[cfg(feature = "std")]
+impl<T: ?Sized, I: InteriorMut<T> + ?Sized> InteriorMut<T> for std::sync::Arc<I> {
+    type Ref<'a> = I::Ref<'a>
+    where
+        Self: 'a, I: 'a;
+
+    type RefMut<'a>=I::RefMut<'a>
+    where
+        Self: 'a, I: 'a;
+
+    type Error<'a>=I::Error<'a>
+    where
+        Self: 'a, I: 'a;
+
+    type ErrorMut<'a>=I::ErrorMut<'a>
+    where
+        Self: 'a, I: 'a;
+
+    fn borrow_int(&self) -> Result<Self::Ref<'_>, Self::Error<'_>> {
+        self.deref().borrow_int()
+    }
+
+    fn borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>> {
+        self.deref().borrow_int_mut()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized, I: TryInteriorMut<T> + ?Sized> TryInteriorMut<T> for std::sync::Arc<I> {
+    type TryError<'a> = I::TryError<'a>
+    where
+        Self: 'a, I: 'a;
+
+    type TryErrorMut<'a> = I::TryErrorMut<'a>
+    where
+        Self: 'a, I: 'a;
+
+    fn try_borrow_int(&self) -> Result<Self::Ref<'_>, Self::TryError<'_>> {
+        self.deref().try_borrow_int()
+    }
+
+    fn try_borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::TryErrorMut<'_>> {
+        self.deref().try_borrow_int_mut()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized, I: InteriorMut<T> + ?Sized> StrongReference<T> for std::sync::Arc<I> {
+    type Weak = std::sync::Weak<I>;
+
+    fn downgrade(&self) -> Self::Weak {
+        std::sync::Arc::downgrade(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized, I: InteriorMut<T> + ?Sized> WeakReference<T> for std::sync::Weak<I> {
+    type Strong = std::sync::Arc<I>;
+
+    fn upgrade(&self) -> Option<Self::Strong> {
+        std::sync::Weak::upgrade(self)
+    }
+}
+
+/// A container pairing a `Mutex` with a `Condvar`, giving [`WaitableInteriorMut`]
+/// support without requiring callers to manage the two separately.
+#[cfg(feature = "std")]
+pub struct Waitable<T> {
+    mutex: std::sync::Mutex<T>,
+    condvar: std::sync::Condvar,
+}
+
+#[cfg(feature = "std")]
+impl<T> Waitable<T> {
+    /// Creates a new waitable container wrapping `value`.
+    pub fn new(value: T) -> Self {
+        Waitable {
+            mutex: std::sync::Mutex::new(value),
+            condvar: std::sync::Condvar::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> InteriorMut<T> for Waitable<T> {
+    type Ref<'a> = std::sync::MutexGuard<'a, T> where T: 'a;
+    type RefMut<'a> = std::sync::MutexGuard<'a, T> where T: 'a;
+    type Error<'a> = std::sync::PoisonError<std::sync::MutexGuard<'a, T>> where T: 'a;
+    type ErrorMut<'a> = std::sync::PoisonError<std::sync::MutexGuard<'a, T>> where T: 'a;
+
+    fn borrow_int(&self) -> Result<Self::Ref<'_>, Self::Error<'_>> {
+        self.mutex.lock()
+    }
+
+    fn borrow_int_mut(&self) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>> {
+        self.mutex.lock()
+    }
+}
+
+#[cfg(feature = "std")]
+impl_interior_swap_via_interior_mut!(Waitable<T>);
+
+#[cfg(feature = "std")]
+impl<T> WaitableInteriorMut<T> for Waitable<T> {
+    fn borrow_int_mut_when(
+        &self,
+        mut condition: impl FnMut(&mut T) -> bool,
+    ) -> Result<Self::RefMut<'_>, Self::ErrorMut<'_>> {
+        let guard = self.mutex.lock()?;
+        self.condvar.wait_while(guard, |value| !condition(value))
+    }
+
+    fn borrow_int_mut_when_timeout(
+        &self,
+        mut condition: impl FnMut(&mut T) -> bool,
+        dur: Duration,
+    ) -> Result<(Self::RefMut<'_>, bool), Self::ErrorMut<'_>> {
+        let guard = self.mutex.lock()?;
+        match self
+            .condvar
+            .wait_timeout_while(guard, dur, |value| !condition(value))
+        {
+            Ok((guard, result)) => Ok((guard, result.timed_out())),
+            Err(poisoned) => {
+                let (guard, _) = poisoned.into_inner();
+                Err(std::sync::PoisonError::new(guard))
+            }
+        }
+    }
+
+    fn notify(&self) {
+        self.condvar.notify_one();
+    }
+
+    fn notify_all(&self) {
+        self.condvar.notify_all();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod waitable_tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn borrow_int_mut_when_blocks_until_notified() {
+        let waitable = Arc::new(Waitable::new(false));
+        let waiter = waitable.clone();
+        let handle = thread::spawn(move || {
+            let guard = waiter.borrow_int_mut_when(|ready| *ready).unwrap();
+            assert!(*guard);
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        *waitable.borrow_int_mut().unwrap() = true;
+        waitable.notify_all();
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn borrow_int_mut_when_timeout_reports_timed_out_if_never_satisfied() {
+        let waitable = Waitable::new(false);
+        let (_guard, timed_out) = waitable
+            .borrow_int_mut_when_timeout(|ready| *ready, Duration::from_millis(20))
+            .unwrap();
+        assert!(timed_out);
+    }
+
+    #[test]
+    fn borrow_int_mut_when_timeout_reports_not_timed_out_if_satisfied_in_time() {
+        let waitable = Arc::new(Waitable::new(false));
+        let waiter = waitable.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            *waiter.borrow_int_mut().unwrap() = true;
+            waiter.notify_all();
+        });
+
+        let (guard, timed_out) = waitable
+            .borrow_int_mut_when_timeout(|ready| *ready, Duration::from_secs(5))
+            .unwrap();
+        assert!(!timed_out);
+        assert!(*guard);
+
+        handle.join().unwrap();
+    }
+}
+
+/// A trait for obtaining a borrow that owns a strong handle to its container, so the
+/// guard can outlive the scope that produced it (e.g. be stored in a struct or returned
+/// after the original handle is dropped).
+///
+/// Whether the returned guard can actually be *sent* to another thread depends on the
+/// underlying container's reference type: `Self::OwnedRef`/`Self::OwnedRefMut` are
+/// `Send` only when `Self::Ref`/`Self::RefMut` are. Notably, `std::sync::MutexGuard`
+/// and `RwLock{Read,Write}Guard` are `!Send`, so `Arc<Mutex<T>>`'s and
+/// `Arc<RwLock<T>>`'s owned guards stay pinned to the thread that created them despite
+/// being `'static` — detaching the lifetime does not make the guard itself `Send`.
+/// Moving a lock across a thread/task boundary this way requires a container whose
+/// `Ref`/`RefMut` are `Send` to begin with; neither container this crate provides
+/// meets that bar today.
+pub trait OwnedInteriorMut<T: ?Sized + 'static>: InteriorMut<T> + Sized + 'static {
+    /// The immutable borrow type, detached from `self`'s lifetime.
+    type OwnedRef: Deref<Target = T>;
+
+    /// The mutable borrow type, detached from `self`'s lifetime.
+    type OwnedRefMut: DerefMut<Target = T>;
+
+    /// Immutably borrows the internal value, consuming `self` to keep it alive
+    /// inside the returned guard instead of borrowing it.
+    fn borrow_int_owned(self) -> Result<Self::OwnedRef, Self::Error<'static>>;
+
+    /// Mutably borrows the internal value, consuming `self` to keep it alive
+    /// inside the returned guard instead of borrowing it.
+    fn borrow_int_owned_mut(self) -> Result<Self::OwnedRefMut, Self::ErrorMut<'static>>;
+}
+
+/// An immutable borrow returned by [`OwnedInteriorMut::borrow_int_owned`] that owns a
+/// strong handle to its container `C`, keeping it alive for as long as the guard lives.
+pub struct OwnedRef<T: ?Sized + 'static, C: InteriorMut<T> + 'static> {
+    guard: mem::ManuallyDrop<C::Ref<'static>>,
+    _container: C,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ?Sized + 'static, C: InteriorMut<T> + 'static> Deref for OwnedRef<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T: ?Sized + 'static, C: InteriorMut<T> + 'static> Drop for OwnedRef<T, C> {
+    fn drop(&mut self) {
+        // SAFETY: dropped exactly once, and before `_container` since `Drop::drop` runs
+        // before any field is dropped.
+        unsafe { mem::ManuallyDrop::drop(&mut self.guard) }
+    }
+}
+
+/// A mutable borrow returned by [`OwnedInteriorMut::borrow_int_owned_mut`] that owns a
+/// strong handle to its container `C`, keeping it alive for as long as the guard lives.
+pub struct OwnedRefMut<T: ?Sized + 'static, C: InteriorMut<T> + 'static> {
+    guard: mem::ManuallyDrop<C::RefMut<'static>>,
+    _container: C,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ?Sized + 'static, C: InteriorMut<T> + 'static> Deref for OwnedRefMut<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T: ?Sized + 'static, C: InteriorMut<T> + 'static> DerefMut for OwnedRefMut<T, C> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T: ?Sized + 'static, C: InteriorMut<T> + 'static> Drop for OwnedRefMut<T, C> {
+    fn drop(&mut self) {
+        // SAFETY: see `OwnedRef`'s `Drop` impl.
+        unsafe { mem::ManuallyDrop::drop(&mut self.guard) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + 'static, I: InteriorMut<T> + ?Sized + 'static> OwnedInteriorMut<T>
+    for std::rc::Rc<I>
+{
+    type OwnedRef = OwnedRef<T, std::rc::Rc<I>>;
+    type OwnedRefMut = OwnedRefMut<T, std::rc::Rc<I>>;
+
+    fn borrow_int_owned(self) -> Result<Self::OwnedRef, Self::Error<'static>> {
+        let raw = std::rc::Rc::into_raw(self);
+        // SAFETY: `raw` was just produced by `Rc::into_raw` above and is turned back
+        // into an `Rc` below, balancing the strong count. Treating it as `&'static I`
+        // in between is sound because the `Rc` stored alongside the guard keeps the
+        // allocation alive for at least as long as the guard exists.
+        let result = unsafe { &*raw }.borrow_int();
+        let container = unsafe { std::rc::Rc::from_raw(raw) };
+        result.map(|guard| OwnedRef {
+            guard: mem::ManuallyDrop::new(guard),
+            _container: container,
+            _marker: PhantomData,
+        })
+    }
+
+    fn borrow_int_owned_mut(self) -> Result<Self::OwnedRefMut, Self::ErrorMut<'static>> {
+        let raw = std::rc::Rc::into_raw(self);
+        // SAFETY: see `borrow_int_owned`.
+        let result = unsafe { &*raw }.borrow_int_mut();
+        let container = unsafe { std::rc::Rc::from_raw(raw) };
+        result.map(|guard| OwnedRefMut {
+            guard: mem::ManuallyDrop::new(guard),
+            _container: container,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + 'static, I: InteriorMut<T> + ?Sized + 'static> OwnedInteriorMut<T>
+    for std::sync::Arc<I>
+{
+    type OwnedRef = OwnedRef<T, std::sync::Arc<I>>;
+    type OwnedRefMut = OwnedRefMut<T, std::sync::Arc<I>>;
+
+    fn borrow_int_owned(self) -> Result<Self::OwnedRef, Self::Error<'static>> {
+        let raw = std::sync::Arc::into_raw(self);
+        // SAFETY: see `Rc`'s `OwnedInteriorMut::borrow_int_owned` impl; the same
+        // reasoning applies since `Arc` also shares one heap allocation across clones.
+        let result = unsafe { &*raw }.borrow_int();
+        let container = unsafe { std::sync::Arc::from_raw(raw) };
+        result.map(|guard| OwnedRef {
+            guard: mem::ManuallyDrop::new(guard),
+            _container: container,
+            _marker: PhantomData,
+        })
+    }
+
+    fn borrow_int_owned_mut(self) -> Result<Self::OwnedRefMut, Self::ErrorMut<'static>> {
+        let raw = std::sync::Arc::into_raw(self);
+        // SAFETY: see `borrow_int_owned`.
+        let result = unsafe { &*raw }.borrow_int_mut();
+        let container = unsafe { std::sync::Arc::from_raw(raw) };
+        result.map(|guard| OwnedRefMut {
+            guard: mem::ManuallyDrop::new(guard),
+            _container: container,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod owned_interior_mut_tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn arc_mutex_owned_ref_mut_releases_lock_on_drop() {
+        let container = Arc::new(Mutex::new(1));
+        let guard = container.clone().borrow_int_owned_mut().unwrap();
+        assert!(container.try_lock().is_err());
+        drop(guard);
+        assert!(container.try_lock().is_ok());
+    }
+
+    #[test]
+    fn rc_refcell_owned_ref_releases_borrow_on_drop() {
+        let container = Rc::new(RefCell::new(1));
+        let guard = container.clone().borrow_int_owned().unwrap();
+        assert!(container.try_borrow_mut().is_err());
+        drop(guard);
+        assert!(container.try_borrow_mut().is_ok());
+    }
+
+    #[test]
+    fn owned_guard_keeps_container_alive_after_original_handle_dropped() {
+        let container = Arc::new(Mutex::new(42));
+        let weak = Arc::downgrade(&container);
+        let owned = container.clone().borrow_int_owned().unwrap();
+        drop(container);
+        assert_eq!(*owned, 42);
+        assert!(weak.upgrade().is_some());
+    }
+
+    #[test]
+    fn owned_ref_mut_write_is_visible_through_owned_ref() {
+        let container = Arc::new(Mutex::new(10));
+        {
+            let mut guard = container.clone().borrow_int_owned_mut().unwrap();
+            *guard = 20;
+        }
+        assert_eq!(*container.borrow_int_owned().unwrap(), 20);
+    }
+}